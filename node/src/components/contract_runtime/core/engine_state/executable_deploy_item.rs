@@ -1,18 +1,65 @@
-use std::fmt::{self, Debug, Display, Formatter};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug, Display, Formatter},
+};
 
 use hex_fmt::HexFmt;
+#[cfg(any(feature = "testing", test))]
+use rand::{
+    distributions::{Alphanumeric, Distribution, Standard},
+    Rng,
+};
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use types::{
-    bytesrepr,
-    contracts::{ContractVersion, DEFAULT_ENTRY_POINT_NAME},
-    ContractHash, ContractPackageHash, Key, RuntimeArgs,
+    account::TransferTarget,
+    bytesrepr::{self, FromBytes, ToBytes},
+    contracts::{
+        ContractPackage, ContractVersion, ContractVersionKey, Group, DEFAULT_ENTRY_POINT_NAME,
+    },
+    AccessRights, ContractHash, ContractPackageHash, EntryPointAccess, Key, ProtocolVersion,
+    RuntimeArgs, StoredValue, URef, U512,
 };
 
 use super::error;
-use crate::components::contract_runtime::{core::execution, shared::account::Account};
+use crate::components::contract_runtime::{
+    core::{execution, tracking_copy::TrackingCopy},
+    shared::{account::Account, newtypes::CorrelationId},
+    storage::global_state::StateReader,
+};
+
+/// Tag byte identifying the `ModuleBytes` variant in the `bytesrepr` encoding.
+const MODULE_BYTES_TAG: u8 = 0;
+/// Tag byte identifying the `StoredContractByHash` variant in the `bytesrepr` encoding.
+const STORED_CONTRACT_BY_HASH_TAG: u8 = 1;
+/// Tag byte identifying the `StoredContractByName` variant in the `bytesrepr` encoding.
+const STORED_CONTRACT_BY_NAME_TAG: u8 = 2;
+/// Tag byte identifying the `StoredVersionedContractByHash` variant in the `bytesrepr` encoding.
+const STORED_VERSIONED_CONTRACT_BY_HASH_TAG: u8 = 3;
+/// Tag byte identifying the `StoredVersionedContractByName` variant in the `bytesrepr` encoding.
+const STORED_VERSIONED_CONTRACT_BY_NAME_TAG: u8 = 4;
+/// Tag byte identifying the `Transfer` variant in the `bytesrepr` encoding.
+const TRANSFER_TAG: u8 = 5;
+
+/// Name of the required "amount" runtime arg on a `Transfer` deploy item.
+const TRANSFER_ARG_AMOUNT: &str = "amount";
+/// Name of the optional "target" runtime arg on a `Transfer` deploy item.
+const TRANSFER_ARG_TARGET: &str = "target";
+/// Name of the optional "id" runtime arg on a `Transfer` deploy item.
+const TRANSFER_ARG_ID: &str = "id";
+
+/// Default ceiling on the amount a single native transfer may move, mirroring the cap placed on
+/// payment amounts; nodes may configure a different value via chainspec.
+///
+/// Set to `u64::MAX` motes, comfortably above the total CSPR supply expressed in motes, so it
+/// rejects only deploys with an implausibly large (almost certainly malformed) transfer amount
+/// rather than ever constraining a real transfer.
+pub const DEFAULT_MAX_TRANSFER_AMOUNT: U512 = U512([u64::MAX, 0, 0, 0, 0, 0, 0, 0]);
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 pub enum ExecutableDeployItem {
     ModuleBytes {
         module_bytes: Vec<u8>,
@@ -160,6 +207,222 @@ impl ExecutableDeployItem {
         }
     }
 
+    /// Resolves this deploy item to the concrete `ContractHash` that the engine should
+    /// execute, reading through `tracking_copy` to follow named keys, contract packages and
+    /// versions.
+    ///
+    /// For the `ByName` variants the account's named keys are consulted first to find the
+    /// underlying hash. For the versioned variants, the `ContractPackage` is loaded and either
+    /// the requested version is looked up (erroring if it is disabled or does not exist) or,
+    /// when no version is specified, the highest enabled version is selected. Entry points
+    /// restricted to one or more groups are rejected unless the calling account holds a `URef`
+    /// granted to one of those groups; see [`Self::is_entry_point_permitted`], which carries the
+    /// unit tests for that decision.
+    ///
+    /// The not-found / disabled-version / no-active-version branches have no unit tests in this
+    /// module, as they need `TrackingCopy`/`StateReader`/`ContractPackage` fixtures. Whether a
+    /// caller's `URef`s satisfy a group-restricted entry point is tested directly via
+    /// `is_entry_point_permitted`, which depends only on plain `Group`/`URef` values.
+    pub(crate) fn resolve_contract_hash<R>(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<R>,
+        account: &Account,
+        protocol_version: ProtocolVersion,
+    ) -> Result<ContractHash, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        match self {
+            ExecutableDeployItem::StoredContractByHash { hash, .. } => Ok(*hash),
+            ExecutableDeployItem::StoredContractByName { name, .. } => {
+                let key = account.named_keys().get(name).cloned().ok_or_else(|| {
+                    error::Error::Exec(execution::Error::NamedKeyNotFound(name.to_string()))
+                })?;
+                key.into_hash()
+                    .map(ContractHash::new)
+                    .ok_or(error::Error::Exec(execution::Error::InvalidKeyVariant))
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash { hash, version, .. } => self
+                .resolve_versioned_contract_hash(
+                    correlation_id,
+                    tracking_copy,
+                    *hash,
+                    *version,
+                    protocol_version,
+                    account,
+                ),
+            ExecutableDeployItem::StoredVersionedContractByName { name, version, .. } => {
+                let key = account.named_keys().get(name).cloned().ok_or_else(|| {
+                    error::Error::Exec(execution::Error::NamedKeyNotFound(name.to_string()))
+                })?;
+                let package_hash = key
+                    .into_hash()
+                    .map(ContractPackageHash::new)
+                    .ok_or(error::Error::Exec(execution::Error::InvalidKeyVariant))?;
+                self.resolve_versioned_contract_hash(
+                    correlation_id,
+                    tracking_copy,
+                    package_hash,
+                    *version,
+                    protocol_version,
+                    account,
+                )
+            }
+            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+                Err(error::Error::InvalidDeployItemVariant(self.to_string()))
+            }
+        }
+    }
+
+    fn resolve_versioned_contract_hash<R>(
+        &self,
+        correlation_id: CorrelationId,
+        tracking_copy: &mut TrackingCopy<R>,
+        package_hash: ContractPackageHash,
+        version: Option<ContractVersion>,
+        protocol_version: ProtocolVersion,
+        account: &Account,
+    ) -> Result<ContractHash, error::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<execution::Error>,
+    {
+        let contract_package: ContractPackage = tracking_copy
+            .get_contract_package(correlation_id, package_hash)
+            .map_err(|_| {
+                error::Error::Exec(execution::Error::ContractPackageNotFound(package_hash))
+            })?;
+
+        let contract_version_key = match version {
+            Some(requested_version) => {
+                ContractVersionKey::new(protocol_version.value().major, requested_version)
+            }
+            None => contract_package.current_contract_version().ok_or(
+                error::Error::Exec(execution::Error::NoActiveContractVersions(package_hash)),
+            )?,
+        };
+
+        if contract_package.is_version_missing(contract_version_key) {
+            return Err(error::Error::Exec(execution::Error::InvalidContractVersion(
+                contract_version_key,
+            )));
+        }
+
+        if contract_package.is_version_disabled(contract_version_key) {
+            return Err(error::Error::Exec(execution::Error::InvalidContractVersion(
+                contract_version_key,
+            )));
+        }
+
+        let contract_hash = contract_package
+            .lookup_contract_hash(contract_version_key)
+            .ok_or(error::Error::Exec(execution::Error::InvalidContractVersion(
+                contract_version_key,
+            )))?;
+
+        let entry_point_access = contract_package
+            .lookup_entry_point_access(contract_version_key, self.entry_point_name());
+        let caller_urefs: Vec<URef> = account
+            .named_keys()
+            .values()
+            .filter_map(Key::as_uref)
+            .copied()
+            .collect();
+        let is_permitted = Self::is_entry_point_permitted(
+            entry_point_access,
+            contract_package.groups(),
+            &caller_urefs,
+        );
+        if !is_permitted {
+            return Err(error::Error::Exec(execution::Error::InvalidContext));
+        }
+
+        Ok(*contract_hash)
+    }
+
+    /// Returns whether `caller_urefs` satisfies `entry_point_access`.
+    ///
+    /// An entry point restricted to one or more groups (`EntryPointAccess::Groups`) is gated by
+    /// URef possession, mirroring the engine's real access-control model: the caller must hold
+    /// at least one of the `URef`s the contract package has granted to *any* of the groups the
+    /// entry point is restricted to, found by name in `group_urefs`. Presenting a group's name
+    /// without possessing one of its `URef`s is not sufficient. Unrestricted entry points (no
+    /// `Groups` access, or no access information at all) are always permitted.
+    fn is_entry_point_permitted(
+        entry_point_access: Option<&EntryPointAccess>,
+        group_urefs: &BTreeMap<Group, BTreeSet<URef>>,
+        caller_urefs: &[URef],
+    ) -> bool {
+        let restricted_groups = match entry_point_access {
+            Some(EntryPointAccess::Groups(restricted_groups)) => restricted_groups,
+            Some(EntryPointAccess::Public) | None => return true,
+        };
+
+        restricted_groups.iter().any(|group| {
+            group_urefs
+                .get(group)
+                .map(|required_urefs| caller_urefs.iter().any(|uref| required_urefs.contains(uref)))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Validates that a `Transfer` deploy item carries the runtime args the native transfer
+    /// handler requires: a `"amount"` of type `U512` no greater than `max_transfer_amount`, an
+    /// optional `"target"` account or purse, and an optional numeric `"id"`.
+    ///
+    /// Non-`Transfer` variants are not subject to this contract and always pass. Rejecting
+    /// malformed transfers here, at acceptance time, is far cheaper than discovering the same
+    /// problem partway through execution.
+    pub fn validate_transfer_args(&self, max_transfer_amount: U512) -> Result<(), error::Error> {
+        let args = match self {
+            ExecutableDeployItem::Transfer { args } => args,
+            ExecutableDeployItem::ModuleBytes { .. }
+            | ExecutableDeployItem::StoredContractByHash { .. }
+            | ExecutableDeployItem::StoredContractByName { .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { .. } => return Ok(()),
+        };
+
+        let runtime_args: RuntimeArgs = bytesrepr::deserialize(args.clone())
+            .map_err(|error| error::Error::Exec(execution::Error::BytesRepr(error)))?;
+
+        let amount: U512 = runtime_args
+            .get(TRANSFER_ARG_AMOUNT)
+            .ok_or_else(|| {
+                error::Error::Exec(execution::Error::MissingArgument {
+                    name: TRANSFER_ARG_AMOUNT.to_string(),
+                })
+            })?
+            .clone()
+            .into_t()
+            .map_err(|error| error::Error::Exec(execution::Error::CLValue(error)))?;
+
+        if amount > max_transfer_amount {
+            return Err(error::Error::Exec(execution::Error::TransferAmountTooLarge {
+                amount,
+                max: max_transfer_amount,
+            }));
+        }
+
+        if let Some(target_arg) = runtime_args.get(TRANSFER_ARG_TARGET) {
+            let _: TransferTarget = target_arg
+                .clone()
+                .into_t()
+                .map_err(|error| error::Error::Exec(execution::Error::CLValue(error)))?;
+        }
+
+        if let Some(id_arg) = runtime_args.get(TRANSFER_ARG_ID) {
+            let _: Option<u64> = id_arg
+                .clone()
+                .into_t()
+                .map_err(|error| error::Error::Exec(execution::Error::CLValue(error)))?;
+        }
+
+        Ok(())
+    }
+
     pub fn into_runtime_args(self) -> Result<RuntimeArgs, bytesrepr::Error> {
         match self {
             ExecutableDeployItem::ModuleBytes { args, .. }
@@ -186,3 +449,453 @@ impl ExecutableDeployItem {
         }
     }
 }
+
+impl ToBytes for ExecutableDeployItem {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                buffer.push(MODULE_BYTES_TAG);
+                buffer.extend(module_bytes.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => {
+                buffer.push(STORED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => {
+                buffer.push(STORED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.push(STORED_VERSIONED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.push(STORED_VERSIONED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::Transfer { args } => {
+                buffer.push(TRANSFER_TAG);
+                buffer.extend(args.to_bytes()?);
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        bytesrepr::U8_SERIALIZED_LENGTH
+            + match self {
+                ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                    module_bytes.serialized_length() + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByHash {
+                    hash,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByName {
+                    name,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::Transfer { args } => args.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for ExecutableDeployItem {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            MODULE_BYTES_TAG => {
+                let (module_bytes, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::ModuleBytes { module_bytes, args },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = ContractHash::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByHash {
+                        hash,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByName {
+                        name,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByHash {
+                        hash,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByName {
+                        name,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            TRANSFER_TAG => {
+                let (args, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((ExecutableDeployItem::Transfer { args }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+#[cfg(any(feature = "testing", test))]
+const RANDOM_BYTES_MAX_LENGTH: usize = 100;
+#[cfg(any(feature = "testing", test))]
+const RANDOM_STRING_MAX_LENGTH: usize = 20;
+
+#[cfg(any(feature = "testing", test))]
+fn random_bytes<R: Rng + ?Sized>(rng: &mut R) -> Vec<u8> {
+    let len = rng.gen_range(0..RANDOM_BYTES_MAX_LENGTH);
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+#[cfg(any(feature = "testing", test))]
+fn random_string<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let len = rng.gen_range(0..RANDOM_STRING_MAX_LENGTH);
+    rng.sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(any(feature = "testing", test))]
+impl Distribution<ExecutableDeployItem> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ExecutableDeployItem {
+        match rng.gen_range(0..6) {
+            0 => ExecutableDeployItem::ModuleBytes {
+                module_bytes: random_bytes(rng),
+                args: random_bytes(rng),
+            },
+            1 => ExecutableDeployItem::StoredContractByHash {
+                hash: ContractHash::new(rng.gen()),
+                entry_point: random_string(rng),
+                args: random_bytes(rng),
+            },
+            2 => ExecutableDeployItem::StoredContractByName {
+                name: random_string(rng),
+                entry_point: random_string(rng),
+                args: random_bytes(rng),
+            },
+            3 => ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: ContractPackageHash::new(rng.gen()),
+                version: rng.gen::<bool>().then(|| rng.gen()),
+                entry_point: random_string(rng),
+                args: random_bytes(rng),
+            },
+            4 => ExecutableDeployItem::StoredVersionedContractByName {
+                name: random_string(rng),
+                version: rng.gen::<bool>().then(|| rng.gen()),
+                entry_point: random_string(rng),
+                args: random_bytes(rng),
+            },
+            5 => ExecutableDeployItem::Transfer {
+                args: random_bytes(rng),
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand_built_variants() -> Vec<ExecutableDeployItem> {
+        vec![
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![1, 2, 3],
+                args: vec![4, 5, 6],
+            },
+            ExecutableDeployItem::StoredContractByHash {
+                hash: ContractHash::new([7; 32]),
+                entry_point: "entry_point".to_string(),
+                args: vec![8, 9],
+            },
+            ExecutableDeployItem::StoredContractByName {
+                name: "name".to_string(),
+                entry_point: "entry_point".to_string(),
+                args: vec![],
+            },
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: ContractPackageHash::new([1; 32]),
+                version: Some(3),
+                entry_point: "entry_point".to_string(),
+                args: vec![1, 2, 3, 4],
+            },
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name: "name".to_string(),
+                version: None,
+                entry_point: "entry_point".to_string(),
+                args: vec![9, 9, 9],
+            },
+            ExecutableDeployItem::Transfer { args: vec![1, 2] },
+        ]
+    }
+
+    #[test]
+    fn should_bytesrepr_round_trip_hand_built_variants() {
+        for item in hand_built_variants() {
+            let serialized = item.to_bytes().expect("should serialize");
+            assert_eq!(serialized.len(), item.serialized_length());
+
+            let (deserialized, remainder) =
+                ExecutableDeployItem::from_bytes(&serialized).expect("should deserialize");
+            assert!(remainder.is_empty());
+            assert_eq!(item, deserialized);
+        }
+    }
+
+    #[test]
+    fn should_reject_unknown_tag() {
+        let bytes = [u8::MAX];
+        assert!(matches!(
+            ExecutableDeployItem::from_bytes(&bytes),
+            Err(bytesrepr::Error::Formatting)
+        ));
+    }
+
+    #[test]
+    fn should_bytesrepr_round_trip_randomly_generated_items() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let item: ExecutableDeployItem = rng.gen();
+
+            let serialized = item.to_bytes().expect("should serialize");
+            assert_eq!(serialized.len(), item.serialized_length());
+
+            let (deserialized, remainder) =
+                ExecutableDeployItem::from_bytes(&serialized).expect("should deserialize");
+            assert!(remainder.is_empty());
+            assert_eq!(item, deserialized);
+        }
+    }
+
+    fn transfer_with_args(args: RuntimeArgs) -> ExecutableDeployItem {
+        ExecutableDeployItem::Transfer {
+            args: args.to_bytes().expect("should serialize runtime args"),
+        }
+    }
+
+    #[test]
+    fn should_reject_transfer_missing_amount() {
+        let item = transfer_with_args(RuntimeArgs::new());
+
+        assert!(matches!(
+            item.validate_transfer_args(DEFAULT_MAX_TRANSFER_AMOUNT),
+            Err(error::Error::Exec(execution::Error::MissingArgument { name }))
+                if name == TRANSFER_ARG_AMOUNT
+        ));
+    }
+
+    #[test]
+    fn should_reject_transfer_amount_above_cap() {
+        let mut args = RuntimeArgs::new();
+        args.insert(TRANSFER_ARG_AMOUNT, DEFAULT_MAX_TRANSFER_AMOUNT + U512::one())
+            .expect("should insert amount");
+        let item = transfer_with_args(args);
+
+        assert!(matches!(
+            item.validate_transfer_args(DEFAULT_MAX_TRANSFER_AMOUNT),
+            Err(error::Error::Exec(execution::Error::TransferAmountTooLarge { .. }))
+        ));
+    }
+
+    #[test]
+    fn should_reject_transfer_amount_with_wrong_cltype() {
+        let mut args = RuntimeArgs::new();
+        args.insert(TRANSFER_ARG_AMOUNT, "not-a-u512")
+            .expect("should insert amount");
+        let item = transfer_with_args(args);
+
+        assert!(matches!(
+            item.validate_transfer_args(DEFAULT_MAX_TRANSFER_AMOUNT),
+            Err(error::Error::Exec(execution::Error::CLValue(_)))
+        ));
+    }
+
+    #[test]
+    fn should_accept_well_formed_transfer() {
+        let mut args = RuntimeArgs::new();
+        args.insert(TRANSFER_ARG_AMOUNT, U512::from(100))
+            .expect("should insert amount");
+        args.insert(TRANSFER_ARG_ID, Some(7u64))
+            .expect("should insert id");
+        let item = transfer_with_args(args);
+
+        assert!(item
+            .validate_transfer_args(DEFAULT_MAX_TRANSFER_AMOUNT)
+            .is_ok());
+    }
+
+    #[test]
+    fn non_transfer_variants_skip_transfer_validation() {
+        let item = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![],
+            args: vec![],
+        };
+
+        assert!(item.validate_transfer_args(U512::zero()).is_ok());
+    }
+
+    fn uref(seed: u8) -> URef {
+        URef::new([seed; 32], AccessRights::READ)
+    }
+
+    #[test]
+    fn should_permit_unrestricted_entry_point() {
+        let group_urefs = BTreeMap::new();
+
+        assert!(ExecutableDeployItem::is_entry_point_permitted(
+            None,
+            &group_urefs,
+            &[],
+        ));
+        assert!(ExecutableDeployItem::is_entry_point_permitted(
+            Some(&EntryPointAccess::Public),
+            &group_urefs,
+            &[],
+        ));
+    }
+
+    #[test]
+    fn should_permit_caller_holding_a_granted_uref() {
+        let admin_group = Group::new("admin");
+        let mut group_urefs = BTreeMap::new();
+        group_urefs.insert(admin_group.clone(), BTreeSet::from([uref(1), uref(2)]));
+        let access = EntryPointAccess::Groups(vec![admin_group]);
+
+        assert!(ExecutableDeployItem::is_entry_point_permitted(
+            Some(&access),
+            &group_urefs,
+            &[uref(2)],
+        ));
+    }
+
+    #[test]
+    fn should_deny_caller_without_a_granted_uref() {
+        let admin_group = Group::new("admin");
+        let mut group_urefs = BTreeMap::new();
+        group_urefs.insert(admin_group.clone(), BTreeSet::from([uref(1)]));
+        let access = EntryPointAccess::Groups(vec![admin_group]);
+
+        // The caller presents some URef, just not one the package granted to "admin".
+        assert!(!ExecutableDeployItem::is_entry_point_permitted(
+            Some(&access),
+            &group_urefs,
+            &[uref(99)],
+        ));
+    }
+
+    #[test]
+    fn should_deny_caller_when_required_group_is_unknown_to_the_package() {
+        let group_urefs = BTreeMap::new();
+        let access = EntryPointAccess::Groups(vec![Group::new("admin")]);
+
+        assert!(!ExecutableDeployItem::is_entry_point_permitted(
+            Some(&access),
+            &group_urefs,
+            &[uref(1)],
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+//! EIP-55-style checksummed hex encoding.
+//!
+//! Plain lowercase hex carries no protection against a flipped or mistyped character: any
+//! 64-character string of hex digits decodes successfully, whether or not it's the hash the
+//! caller meant to type. Checksummed hex folds a few bits of the hash of the data itself into
+//! the casing of the hex string, so a decoder can detect (with high probability) when a
+//! hand-typed or copy-pasted hash has been mangled, without changing the decoded value.
+//!
+//! `BlockHash`, `DeployHash`, `ContractHash` and public keys do not use this codec in their
+//! `Display`/`FromStr`/serde paths. `encode`/`decode` below are only unit-tested in isolation.
+
+use super::{error::Error, hash};
+
+/// Encodes `input` as checksummed hex.
+///
+/// The raw input bytes are blake2b-hashed, and each alphabetic hex character is uppercased if
+/// the corresponding bit of the hash (consuming one bit per hex character, high bit first, and
+/// wrapping back to the start of the digest if `input` needs more bits than the digest has) is
+/// set.
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    let input = input.as_ref();
+    let lowercase_hex = hex::encode(input);
+    let checksum = hash::hash(input);
+    let checksum_bytes = checksum.as_ref();
+
+    lowercase_hex
+        .chars()
+        .enumerate()
+        .map(|(index, character)| {
+            if character.is_ascii_alphabetic() && bit_is_set(checksum_bytes, index) {
+                character.to_ascii_uppercase()
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+/// Decodes `input`, which may be plain lowercase hex or checksummed hex as produced by
+/// [`encode`].
+///
+/// All-lowercase input is accepted unconditionally. Input containing any uppercase letter is
+/// checksum-verified by re-encoding the decoded bytes and comparing casing; a mismatch returns
+/// [`Error::InvalidChecksum`].
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, Error> {
+    let input = input.as_ref();
+    let bytes = hex::decode(input)?;
+
+    let has_uppercase = input.iter().any(u8::is_ascii_uppercase);
+    if has_uppercase && encode(&bytes).as_bytes() != input {
+        return Err(Error::InvalidChecksum);
+    }
+
+    Ok(bytes)
+}
+
+/// Returns whether the bit at `index` of `hash_bytes` is set, consuming one bit per hex
+/// character, most-significant bit first within each byte, and wrapping around to the start of
+/// `hash_bytes` once `index` exceeds the number of bits it holds.
+fn bit_is_set(hash_bytes: &[u8], index: usize) -> bool {
+    let index = index % (hash_bytes.len() * 8);
+    let byte = hash_bytes[index / 8];
+    let bit_index = 7 - (index % 8);
+    (byte >> bit_index) & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_via_checksummed_encoding() {
+        let original = vec![1u8, 2, 3, 4, 5, 250, 251, 252, 253, 254, 255];
+
+        let checksummed = encode(&original);
+
+        assert_eq!(decode(&checksummed).unwrap(), original);
+    }
+
+    #[test]
+    fn should_accept_all_lowercase() {
+        let original = vec![0u8; 32];
+        let lowercase = hex::encode(&original);
+
+        assert_eq!(decode(&lowercase).unwrap(), original);
+    }
+
+    #[test]
+    fn should_uppercase_exactly_the_characters_whose_checksum_bit_is_set() {
+        let original = vec![0xABu8, 0xCD, 0xEF, 0x01, 0x23, 0x45];
+        let lowercase_hex = hex::encode(&original);
+        let checksum = hash::hash(&original);
+        let checksum_bytes = checksum.as_ref();
+
+        let checksummed = encode(&original);
+
+        for (index, (checksummed_char, lowercase_char)) in
+            checksummed.chars().zip(lowercase_hex.chars()).enumerate()
+        {
+            assert_eq!(checksummed_char.to_ascii_lowercase(), lowercase_char);
+            if lowercase_char.is_ascii_alphabetic() {
+                assert_eq!(
+                    checksummed_char.is_ascii_uppercase(),
+                    bit_is_set(checksum_bytes, index),
+                    "character at index {} did not match the expected checksum bit",
+                    index
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn should_reject_mismatched_checksum() {
+        let original = vec![0xDEu8, 0xAD, 0xBE, 0xEF];
+        let checksummed = encode(&original);
+
+        let tampered_index = checksummed
+            .chars()
+            .position(char::is_ascii_alphabetic)
+            .expect("fixture should contain at least one alphabetic hex character");
+        let tampered_char = checksummed.chars().nth(tampered_index).unwrap();
+        let flipped_char = if tampered_char.is_ascii_uppercase() {
+            tampered_char.to_ascii_lowercase()
+        } else {
+            tampered_char.to_ascii_uppercase()
+        };
+        let mut tampered = checksummed;
+        tampered.replace_range(tampered_index..=tampered_index, &flipped_char.to_string());
+
+        assert_eq!(decode(&tampered).unwrap_err(), Error::InvalidChecksum);
+    }
+}
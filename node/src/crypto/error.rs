@@ -32,6 +32,10 @@ pub enum Error {
     /// Pem format error.
     #[error("pem error: {0}")]
     FromPem(String),
+    /// Error resulting when a checksummed-hex-encoded input's casing doesn't match the
+    /// checksum recomputed from its bytes.
+    #[error("invalid checksum")]
+    InvalidChecksum,
 }
 
 impl From<SignatureError> for Error {
@@ -13,12 +13,16 @@ mod peers_map;
 mod status_feed;
 mod timestamp;
 
-use std::{fmt::Display, ops::Deref};
+use std::{fmt::Display, ops::Deref, sync::Arc};
 
 use rand::{CryptoRng, RngCore};
 #[cfg(not(test))]
 use rand_chacha::ChaCha20Rng;
 
+// Note: under the `json-schema` feature, `JsonSchema` is derived for `ExecutableDeployItem`
+// (see `engine_state::executable_deploy_item`) only. `json_compatibility::JsonBlock` and the
+// other JSON-facing types re-exported from this module (`Deploy`, `ChainspecInfo`,
+// `GetStatusResult`, `PeersMap`) do not implement `JsonSchema`.
 pub use block::{
     json_compatibility::JsonBlock, Block, BlockBody, BlockHash, BlockHeader, BlockSignatures,
     BlockValidationError, FinalitySignature,
@@ -59,10 +63,16 @@ pub type NodeRng = crate::testing::TestRng;
 ///
 /// This type exists solely to switch between `Box` and `Arc` based behavior, future updates should
 /// deprecate this in favor of using `Arc`s directly or turning `LoadedItem` into a newtype.
-#[derive(DataSize, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(DataSize, Debug)]
 pub enum LoadedItem<T> {
     /// An owned copy of the object.
     Owned(Box<T>),
+    /// A reference-counted, shared copy of the object.
+    ///
+    /// Used when the same immutable, content-addressed object is handed to multiple
+    /// components (e.g. gossip, consensus and storage all receiving the same block or deploy)
+    /// so they can share a single allocation instead of each holding their own copy.
+    Shared(Arc<T>),
 }
 
 impl<T> Deref for LoadedItem<T> {
@@ -72,10 +82,47 @@ impl<T> Deref for LoadedItem<T> {
     fn deref(&self) -> &Self::Target {
         match self {
             LoadedItem::Owned(obj) => &*obj,
+            LoadedItem::Shared(obj) => &*obj,
         }
     }
 }
 
+// `Owned` and `Shared` are just different representations of the same logical object, so
+// equality and ordering are defined in terms of the dereferenced value rather than the derived,
+// variant-then-field comparison, which would treat `Owned(Box::new(v))` and
+// `Shared(Arc::new(v))` as unequal/incomparable for an identical `v`.
+impl<T> PartialEq for LoadedItem<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> Eq for LoadedItem<T> where T: Eq {}
+
+impl<T> PartialOrd for LoadedItem<T>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T> Ord for LoadedItem<T>
+where
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
 impl<T> LoadedItem<T> {
     /// Creates a new owned instance of the object.
     #[inline]
@@ -83,14 +130,27 @@ impl<T> LoadedItem<T> {
         LoadedItem::Owned(Box::new(inner))
     }
 
+    /// Creates a new shared instance of the object.
+    #[inline]
+    pub(crate) fn shared_new(inner: Arc<T>) -> Self {
+        LoadedItem::Shared(inner)
+    }
+
     /// Converts a loaded object into an instance of `T`.
     ///
-    /// May clone the object as a result. This method should not be used in new code, it exists
-    /// solely to bridge old interfaces with the `LoadedItem`.
+    /// For a `Shared` instance this only clones the object if another handle to the same `Arc`
+    /// is still alive; otherwise the sole allocation is reused. This method should not be used
+    /// in new code, it exists solely to bridge old interfaces with the `LoadedItem`.
     #[inline]
-    pub(crate) fn into_inner(self) -> T {
+    pub(crate) fn into_inner(self) -> T
+    where
+        T: Clone,
+    {
         match self {
             LoadedItem::Owned(inner) => *inner,
+            LoadedItem::Shared(inner) => {
+                Arc::try_unwrap(inner).unwrap_or_else(|shared| (*shared).clone())
+            }
         }
     }
 }
@@ -103,6 +163,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LoadedItem::Owned(inner) => inner.fmt(f),
+            LoadedItem::Shared(inner) => inner.fmt(f),
         }
     }
 }
@@ -118,6 +179,7 @@ where
     {
         match self {
             LoadedItem::Owned(inner) => inner.serialize(serializer),
+            LoadedItem::Shared(inner) => inner.serialize(serializer),
         }
     }
 }
@@ -134,3 +196,28 @@ where
         T::deserialize(deserializer).map(LoadedItem::owned_new)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::LoadedItem;
+
+    #[test]
+    fn owned_and_shared_loaded_items_with_equal_values_are_equal() {
+        let owned = LoadedItem::Owned(Box::new(42));
+        let shared = LoadedItem::shared_new(Arc::new(42));
+
+        assert_eq!(owned, shared);
+        assert_eq!(owned.cmp(&shared), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn owned_and_shared_loaded_items_with_differing_values_are_not_equal() {
+        let owned = LoadedItem::Owned(Box::new(1));
+        let shared = LoadedItem::shared_new(Arc::new(2));
+
+        assert_ne!(owned, shared);
+        assert_eq!(owned.cmp(&shared), std::cmp::Ordering::Less);
+    }
+}